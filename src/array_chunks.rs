@@ -0,0 +1,69 @@
+use crate::IteratorFixed;
+
+/// A fixed size iterator that groups consecutive elements into `[Item; C]` arrays, produced by
+/// [`array_chunks`](IteratorFixed::array_chunks).
+pub struct ArrayChunks<I, const C: usize> {
+    iter: I,
+}
+
+/// Helper used to reject invalid const generic combinations at compile time.
+///
+/// `generic_const_exprs` does not support `const { assert!(...) }` blocks inside a generic
+/// constant, so this is the usual workaround: a bound of `Assert<{ some bool expr }>: IsTrue` only
+/// resolves when the expression evaluates to `true`.
+#[doc(hidden)]
+pub struct Assert<const COND: bool>;
+
+#[doc(hidden)]
+pub trait IsTrue {}
+
+impl IsTrue for Assert<true> {}
+
+impl<I: Iterator, const C: usize> Iterator for ArrayChunks<I, C> {
+    type Item = [I::Item; C];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut first = Some(self.iter.next()?);
+        Some(core::array::from_fn(|_| match first.take() {
+            Some(item) => item,
+            None => self
+                .iter
+                .next()
+                .expect("ArrayChunks: source iterator ended in the middle of a chunk"),
+        }))
+    }
+}
+
+#[cfg(feature = "nightly_features")]
+impl<I: Iterator, const N: usize> IteratorFixed<I, N> {
+    /// Creates a fixed size iterator that groups consecutive elements of the source into
+    /// `[Item; C]` arrays, yielding `N / C` chunks.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 2] = [1, 2, 3, 4].into_iter_fixed().array_chunks::<2>().collect();
+    /// assert_eq!(a, [[1, 2], [3, 4]]);
+    /// ```
+    ///
+    /// `N` must be a multiple of `C`, otherwise the call fails to compile rather than silently
+    /// dropping the leftover elements:
+    /// ```compile_fail
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// // 4 is not a multiple of 3, so this does not compile
+    /// let a = [1, 2, 3, 4].into_iter_fixed().array_chunks::<3>();
+    /// ```
+    #[inline]
+    pub fn array_chunks<const C: usize>(self) -> IteratorFixed<ArrayChunks<I, C>, { N / C }>
+    where
+        Assert<{ N.is_multiple_of(C) }>: IsTrue,
+    {
+        // Safety: the bound above guarantees N is a multiple of C, and the source yields exactly
+        // N elements, so pulling C elements per chunk yields exactly N / C chunks with nothing
+        // left over
+        unsafe { IteratorFixed::from_iter(ArrayChunks { iter: self.inner }) }
+    }
+}
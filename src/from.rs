@@ -0,0 +1,22 @@
+use crate::IteratorFixed;
+
+/// Conversion from an [`IteratorFixed`] of a known length.
+///
+/// By implementing `FromIteratorFixed` for a type, you define how it will be created from a
+/// fixed size iterator. This is used to implement [`collect`](IteratorFixed::collect).
+pub trait FromIteratorFixed<T, const N: usize>: Sized {
+    /// Creates a value from a fixed size iterator.
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter: IteratorFixed<I, N>) -> Self;
+}
+
+impl<T, const N: usize> FromIteratorFixed<T, N> for [T; N] {
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter: IteratorFixed<I, N>) -> Self {
+        let mut iter = iter;
+        core::array::from_fn(|_| {
+            iter.next().expect(
+                "IteratorFixed<_, N> did not yield exactly N elements, this is a bug in the \
+                 `IntoIteratorFixed`/adapter implementation being used",
+            )
+        })
+    }
+}
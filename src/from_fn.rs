@@ -0,0 +1,21 @@
+use core::iter::Map;
+use core::ops::Range;
+
+use crate::IteratorFixed;
+
+/// Creates a fixed size iterator where each successive item is produced by calling `f` with the
+/// element's index.
+///
+/// Basic usage:
+/// ```
+/// let a: [_; 4] = iter_fixed::from_fn::<_, _, 4>(|i| i * i).collect();
+/// assert_eq!(a, [0, 1, 4, 9]);
+/// ```
+#[inline]
+pub fn from_fn<F, T, const N: usize>(f: F) -> IteratorFixed<Map<Range<usize>, F>, N>
+where
+    F: FnMut(usize) -> T,
+{
+    // Safety: (0..N) yields exactly N elements, so mapping over it yields exactly N elements
+    unsafe { IteratorFixed::from_iter((0..N).map(f)) }
+}
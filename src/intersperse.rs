@@ -0,0 +1,63 @@
+use core::iter::Peekable;
+
+use crate::IteratorFixed;
+
+/// A fixed size iterator that places a copy of a separator between adjacent items, produced by
+/// [`intersperse`](IteratorFixed::intersperse).
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    separator_next: bool,
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.separator_next && self.iter.peek().is_some() {
+            self.separator_next = false;
+            Some(self.separator.clone())
+        } else {
+            self.separator_next = true;
+            self.iter.next()
+        }
+    }
+}
+
+#[cfg(feature = "nightly_features")]
+impl<I: Iterator, const N: usize> IteratorFixed<I, N> {
+    /// Creates a fixed size iterator which places a copy of `separator` between adjacent items of
+    /// the original iterator.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 5] = [1, 2, 3].into_iter_fixed().intersperse(0).collect();
+    /// assert_eq!(a, [1, 0, 2, 0, 3]);
+    /// ```
+    #[inline]
+    pub fn intersperse(
+        self,
+        separator: I::Item,
+    ) -> IteratorFixed<Intersperse<I>, { N.saturating_sub(1) + N }>
+    where
+        I::Item: Clone,
+    {
+        // Safety: interspersing a separator between N elements yields N elements plus N - 1
+        // separators, i.e. N.saturating_sub(1) + N elements in total. `2 * N - 1` overflows when
+        // N == 0, so the subtraction is saturated instead.
+        unsafe {
+            IteratorFixed::from_iter(Intersperse {
+                iter: self.inner.peekable(),
+                separator,
+                separator_next: false,
+            })
+        }
+    }
+}
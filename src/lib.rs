@@ -0,0 +1,325 @@
+//! `iter_fixed` provides [`IteratorFixed`], an iterator adapter that keeps track, at the type
+//! level, of the exact number of elements it will yield.
+//!
+//! Knowing the length of an iterator up front lets us do things that are normally only possible
+//! for arrays and other fixed size containers, such as [`map`](IteratorFixed::map)-ing and
+//! [`zip`](IteratorFixed::zip)-ing without losing the fixed size, and then
+//! [`collect`](IteratorFixed::collect)-ing straight back into an array, with no risk of a
+//! length-mismatch panic.
+//!
+//! ```
+//! use iter_fixed::IntoIteratorFixed;
+//!
+//! let a = [1, 2, 3];
+//! let b = [4, 5, 6];
+//!
+//! let sum: [_; 3] = a
+//!     .into_iter_fixed()
+//!     .zip(b)
+//!     .map(|(a, b)| a + b)
+//!     .collect();
+//!
+//! assert_eq!(sum, [5, 7, 9]);
+//! ```
+#![no_std]
+#![cfg_attr(feature = "nightly_features", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly_features", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly_features", feature(trusted_len))]
+
+#[cfg(feature = "nightly_features")]
+mod array_chunks;
+mod from;
+mod from_fn;
+#[cfg(feature = "nightly_features")]
+mod intersperse;
+mod into;
+mod scan;
+
+#[cfg(feature = "nightly_features")]
+pub use array_chunks::ArrayChunks;
+pub use from::FromIteratorFixed;
+pub use from_fn::from_fn;
+#[cfg(feature = "nightly_features")]
+pub use intersperse::Intersperse;
+pub use into::IntoIteratorFixed;
+pub use scan::Scan;
+
+use core::iter::{Enumerate, Map, Rev, Zip};
+#[cfg(feature = "nightly_features")]
+use core::iter::{Chain, FlatMap, Flatten, Skip, StepBy, Take};
+
+/// An iterator adapter that is guaranteed, at the type level, to yield exactly `N` elements.
+///
+/// `IteratorFixed<I, N>` cannot be constructed directly; instead it is obtained by calling
+/// [`into_iter_fixed`](IntoIteratorFixed::into_iter_fixed) on a type that implements
+/// [`IntoIteratorFixed`], such as `[T; N]`, or by calling [`from_fn`].
+///
+/// # Safety
+/// The inner iterator is relied upon to yield exactly `N` elements. Every adapter defined on
+/// `IteratorFixed` must uphold this invariant.
+pub struct IteratorFixed<I, const N: usize> {
+    inner: I,
+}
+
+impl<I: Iterator, const N: usize> IteratorFixed<I, N> {
+    /// Creates a new `IteratorFixed` from `iter` without checking that it actually yields
+    /// exactly `N` elements.
+    ///
+    /// # Safety
+    /// The caller must guarantee that `iter` yields exactly `N` elements.
+    #[inline]
+    pub(crate) unsafe fn from_iter(iter: I) -> Self {
+        Self { inner: iter }
+    }
+
+    /// Takes a closure and creates a fixed size iterator which calls that closure on each
+    /// element.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [i32; 3] = [1, 2, 3].into_iter_fixed().map(|x| x * 2).collect();
+    /// assert_eq!(a, [2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn map<F, B>(self, f: F) -> IteratorFixed<Map<I, F>, N>
+    where
+        F: FnMut(I::Item) -> B,
+    {
+        // Safety: Map yields exactly as many elements as its source
+        unsafe { IteratorFixed::from_iter(self.inner.map(f)) }
+    }
+
+    /// 'Zips up' two fixed size iterators into a single fixed size iterator of pairs.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let zipped: [_; 3] = [1, 2, 3].into_iter_fixed().zip([4, 5, 6]).collect();
+    /// assert_eq!(zipped, [(1, 4), (2, 5), (3, 6)]);
+    /// ```
+    #[inline]
+    pub fn zip<U>(self, other: U) -> IteratorFixed<Zip<I, U::IntoIter>, N>
+    where
+        U: IntoIteratorFixed<N>,
+    {
+        // Safety: zipping two iterators that both yield exactly N elements yields exactly N pairs
+        unsafe { IteratorFixed::from_iter(self.inner.zip(other.into_iter_fixed().inner)) }
+    }
+
+    /// Creates a fixed size iterator which gives the current iteration count as well as the
+    /// next value.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 3] = ['a', 'b', 'c'].into_iter_fixed().enumerate().collect();
+    /// assert_eq!(a, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    #[inline]
+    pub fn enumerate(self) -> IteratorFixed<Enumerate<I>, N> {
+        // Safety: Enumerate yields exactly as many elements as its source
+        unsafe { IteratorFixed::from_iter(self.inner.enumerate()) }
+    }
+
+    /// Creates a fixed size iterator that iterates over the elements in reverse order.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 3] = [1, 2, 3].into_iter_fixed().rev().collect();
+    /// assert_eq!(a, [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn rev(self) -> IteratorFixed<Rev<I>, N>
+    where
+        I: DoubleEndedIterator,
+    {
+        // Safety: Rev yields exactly as many elements as its source
+        unsafe { IteratorFixed::from_iter(self.inner.rev()) }
+    }
+
+    /// Transforms the fixed size iterator into a collection whose size is known at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [i32; 3] = [1, 2, 3].into_iter_fixed().collect();
+    /// assert_eq!(a, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn collect<B>(self) -> B
+    where
+        B: FromIteratorFixed<I::Item, N>,
+    {
+        B::from_iter_fixed(self)
+    }
+}
+
+#[cfg(feature = "nightly_features")]
+impl<I: Iterator, const N: usize> IteratorFixed<I, N> {
+    /// Creates a fixed size iterator that skips the first `M` elements.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 3] = [1, 2, 3, 4].into_iter_fixed().skip::<1>().collect();
+    /// assert_eq!(a, [2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn skip<const M: usize>(self) -> IteratorFixed<Skip<I>, { N - M }> {
+        // Safety: skipping M elements off an iterator that yields exactly N elements yields
+        // exactly N - M elements
+        unsafe { IteratorFixed::from_iter(self.inner.skip(M)) }
+    }
+
+    /// Creates a fixed size iterator starting at the same point, but stepping by `M` elements at
+    /// a time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 3] = [1, 2, 3, 4, 5].into_iter_fixed().step_by::<2>().collect();
+    /// assert_eq!(a, [1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn step_by<const M: usize>(self) -> IteratorFixed<StepBy<I>, { N.div_ceil(M) }> {
+        // Safety: stepping by M elements over an iterator that yields exactly N elements yields
+        // exactly ceil(N / M) elements
+        unsafe { IteratorFixed::from_iter(self.inner.step_by(M)) }
+    }
+
+    /// Creates a fixed size iterator that yields only the first `M` elements.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 2] = [1, 2, 3, 4].into_iter_fixed().take::<2>().collect();
+    /// assert_eq!(a, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn take<const M: usize>(self) -> IteratorFixed<Take<I>, M>
+    where
+        [(); N - M]:,
+    {
+        // Safety: the bound above guarantees N >= M, and taking M elements off an iterator that
+        // yields exactly N >= M elements yields exactly M elements
+        unsafe { IteratorFixed::from_iter(self.inner.take(M)) }
+    }
+
+    /// Takes two fixed size iterators and creates a new fixed size iterator over both in
+    /// sequence.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 4] = [1, 2].into_iter_fixed().chain([3, 4]).collect();
+    /// assert_eq!(a, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn chain<U, const M: usize>(
+        self,
+        other: U,
+    ) -> IteratorFixed<Chain<I, U::IntoIter>, { N + M }>
+    where
+        U: IntoIteratorFixed<M, Item = I::Item>,
+    {
+        // Safety: chaining two iterators that yield exactly N and M elements yields exactly
+        // N + M elements
+        unsafe { IteratorFixed::from_iter(self.inner.chain(other.into_iter_fixed().inner)) }
+    }
+
+    /// Creates a fixed size iterator that flattens nested, fixed size structure.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 4] = [[1, 2], [3, 4]].into_iter_fixed().flatten().collect();
+    /// assert_eq!(a, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn flatten<const M: usize>(self) -> IteratorFixed<Flatten<I>, { N * M }>
+    where
+        I::Item: IntoIteratorFixed<M> + IntoIterator,
+    {
+        // Safety: flattening N inner iterators that each yield exactly M elements yields exactly
+        // N * M elements
+        unsafe { IteratorFixed::from_iter(self.inner.flatten()) }
+    }
+
+    /// Creates a fixed size iterator that works like [`map`](IteratorFixed::map), but flattens
+    /// the fixed size iterator produced by the closure.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 6] = [1, 2, 3].into_iter_fixed().flat_map(|x| [x, x]).collect();
+    /// assert_eq!(a, [1, 1, 2, 2, 3, 3]);
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn flat_map<F, U, const M: usize>(
+        self,
+        mut f: F,
+    ) -> IteratorFixed<
+        FlatMap<I, IteratorFixed<U::IntoIter, M>, impl FnMut(I::Item) -> IteratorFixed<U::IntoIter, M>>,
+        { N * M },
+    >
+    where
+        F: FnMut(I::Item) -> U,
+        U: IntoIteratorFixed<M>,
+    {
+        // Safety: mapping each of the N source elements to a fixed size iterator of M elements
+        // and flattening the result yields exactly N * M elements
+        unsafe {
+            IteratorFixed::from_iter(self.inner.flat_map(move |item| f(item).into_iter_fixed()))
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for IteratorFixed<I, N> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeIterator for IteratorFixed<I, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<I: DoubleEndedIterator, const N: usize> DoubleEndedIterator for IteratorFixed<I, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.inner.next_back()
+    }
+}
+
+// Safety: IteratorFixed<I, N> yields exactly N elements whenever I does, and I: TrustedLen
+// guarantees that its `size_hint` exact bound is honest, so the same guarantee carries over.
+#[cfg(feature = "nightly_features")]
+unsafe impl<I: core::iter::TrustedLen, const N: usize> core::iter::TrustedLen
+    for IteratorFixed<I, N>
+{
+}
@@ -0,0 +1,68 @@
+use crate::IteratorFixed;
+
+/// A fixed size iterator that maintains state while iterating another iterator, produced by
+/// [`scan`](IteratorFixed::scan).
+///
+/// Unlike [`core::iter::Scan`], the closure returns `B` directly rather than `Option<B>`, so the
+/// length of the source iterator is always preserved.
+pub struct Scan<I, St, F> {
+    iter: I,
+    state: St,
+    f: F,
+}
+
+impl<I, St, F, B> Iterator for Scan<I, St, F>
+where
+    I: Iterator,
+    F: FnMut(&mut St, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        let item = self.iter.next()?;
+        Some((self.f)(&mut self.state, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator, const N: usize> IteratorFixed<I, N> {
+    /// An adapter which, like [`map`](IteratorFixed::map), applies a closure to each element,
+    /// while additionally carrying some mutable state between iterations.
+    ///
+    /// Unlike [`Iterator::scan`], the closure returns `B` directly instead of `Option<B>`, so the
+    /// fixed length `N` is always preserved.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [_; 4] = [1, 2, 3, 4]
+    ///     .into_iter_fixed()
+    ///     .scan(0, |sum, x| {
+    ///         *sum += x;
+    ///         *sum
+    ///     })
+    ///     .collect();
+    /// assert_eq!(a, [1, 3, 6, 10]);
+    /// ```
+    #[inline]
+    pub fn scan<St, F, B>(self, init: St, f: F) -> IteratorFixed<Scan<I, St, F>, N>
+    where
+        F: FnMut(&mut St, I::Item) -> B,
+    {
+        // Safety: Scan yields exactly as many elements as its source, since the closure always
+        // returns an item rather than optionally skipping one
+        unsafe {
+            IteratorFixed::from_iter(Scan {
+                iter: self.inner,
+                state: init,
+                f,
+            })
+        }
+    }
+}
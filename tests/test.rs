@@ -22,6 +22,91 @@ fn test() {
     assert_eq!(res, [(1, 42), (2, 42), (3, 42)]);
 }
 
+#[test]
+fn test_enumerate() {
+    let res: [_; 3] = ['a', 'b', 'c'].into_iter_fixed().enumerate().collect();
+
+    assert_eq!(res, [(0, 'a'), (1, 'b'), (2, 'c')]);
+
+    let res: [_; 0] = ([] as [char; 0]).into_iter_fixed().enumerate().collect();
+
+    assert_eq!(res, []);
+}
+
+#[test]
+fn test_rev() {
+    let a: [_; 3] = [1, 2, 3].into_iter_fixed().rev().collect();
+
+    assert_eq!(a, [3, 2, 1]);
+
+    // rev() stays an ExactSizeIterator/DoubleEndedIterator, so it can be driven from both ends
+    let mut iter = [1, 2, 3, 4].into_iter_fixed().rev();
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.len(), 2);
+
+    // composes with enumerate(): reversing first flips the values, indices still count up
+    let res: [_; 3] = ['a', 'b', 'c']
+        .into_iter_fixed()
+        .rev()
+        .enumerate()
+        .collect();
+
+    assert_eq!(res, [(0, 'c'), (1, 'b'), (2, 'a')]);
+
+    let res: [_; 0] = ([] as [char; 0]).into_iter_fixed().rev().collect();
+
+    assert_eq!(res, []);
+}
+
+#[test]
+fn test_scan() {
+    let a: [_; 4] = [1, 2, 3, 4]
+        .into_iter_fixed()
+        .scan(0, |sum, x| {
+            *sum += x;
+            *sum
+        })
+        .collect();
+
+    assert_eq!(a, [1, 3, 6, 10]);
+
+    // composes with zip(): running sum of the product of the two source iterators
+    let a: [_; 3] = [1, 2, 3]
+        .into_iter_fixed()
+        .zip([4, 5, 6])
+        .scan(0, |sum, (a, b)| {
+            *sum += a * b;
+            *sum
+        })
+        .collect();
+
+    assert_eq!(a, [4, 14, 32]);
+
+    let a: [_; 0] = ([] as [i32; 0])
+        .into_iter_fixed()
+        .scan(0, |sum, x| {
+            *sum += x;
+            *sum
+        })
+        .collect();
+
+    assert_eq!(a, []);
+}
+
+#[test]
+fn test_from_fn() {
+    let a: [_; 4] = iter_fixed::from_fn::<_, _, 4>(|i| i * i).collect();
+
+    assert_eq!(a, [0, 1, 4, 9]);
+
+    let a: [usize; 0] = iter_fixed::from_fn::<_, _, 0>(|i| i * i).collect();
+
+    assert_eq!(a, []);
+}
+
 #[cfg(feature = "nightly_features")]
 #[test]
 fn test_changing_length() {
@@ -70,3 +155,33 @@ fn test_changing_length() {
 
     assert_eq!(res, [1, 1, 2, 2, 3, 3]);
 }
+
+#[cfg(feature = "nightly_features")]
+#[test]
+fn test_intersperse() {
+    let res: [_; 5] = [1, 2, 3].into_iter_fixed().intersperse(0).collect();
+
+    assert_eq!(res, [1, 0, 2, 0, 3]);
+
+    // a single element needs no separator
+    let res: [_; 1] = [1].into_iter_fixed().intersperse(0).collect();
+
+    assert_eq!(res, [1]);
+
+    // an empty iterator stays empty; this used to overflow computing 2 * N - 1 for N == 0
+    let res: [_; 0] = ([] as [i32; 0]).into_iter_fixed().intersperse(0).collect();
+
+    assert_eq!(res, []);
+}
+
+#[cfg(feature = "nightly_features")]
+#[test]
+fn test_array_chunks() {
+    let res: [_; 2] = [1, 2, 3, 4].into_iter_fixed().array_chunks::<2>().collect();
+
+    assert_eq!(res, [[1, 2], [3, 4]]);
+
+    let res: [[i32; 2]; 0] = ([] as [i32; 0]).into_iter_fixed().array_chunks::<2>().collect();
+
+    assert_eq!(res, [] as [[i32; 2]; 0]);
+}